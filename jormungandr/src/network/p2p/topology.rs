@@ -6,12 +6,747 @@ use crate::{
     network::p2p::{Gossips, Id, Node, Policy, PolicyConfig},
     settings::start::network::Configuration,
 };
+use chain_crypto::{Ed25519, PublicKey, SecretKey, Signature, Verification};
 use poldercast::{
     poldercast::{Cyclon, Rings, Vicinity},
     Layer, NodeProfile, PolicyReport, StrikeReason, Topology,
 };
+use rand::{seq::SliceRandom, Rng};
 use slog::Logger;
-use std::sync::{Arc, RwLock};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::{Hash, Hasher},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, RwLock,
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+fn now_unix() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+/// `true` if a record last refreshed at `last_seen` counts as stale at
+/// `now`, i.e. older than `max_age`. A clock that appears to have gone
+/// backwards (`now < last_seen`) is treated as not stale, rather than
+/// stale, since that's an artifact of clock skew, not actual silence.
+fn is_stale(now: Duration, last_seen: Duration, max_age: Duration) -> bool {
+    now.checked_sub(last_seen)
+        .map_or(false, |age| age > max_age)
+}
+
+/// Keep only the last item in `items` seen for each key produced by
+/// `key_fn`, preserving each surviving key's original position.
+///
+/// Used by [`P2pTopology::accept_gossips`] to implement last-version-wins
+/// when the same id shows up more than once within a single gossip batch.
+fn dedupe_keep_last_by_key<T, K: Eq + Hash + Clone>(
+    items: Vec<T>,
+    key_fn: impl Fn(&T) -> K,
+) -> Vec<T> {
+    let mut order: Vec<K> = Vec::new();
+    let mut last: HashMap<K, T> = HashMap::new();
+    for item in items {
+        let key = key_fn(&item);
+        if !last.contains_key(&key) {
+            order.push(key.clone());
+        }
+        last.insert(key, item);
+    }
+    order
+        .into_iter()
+        .filter_map(|key| last.remove(&key))
+        .collect()
+}
+
+/// Restrict `candidates` to the ids poldercast's own admission policy
+/// actually kept as an available or quarantined node.
+///
+/// A gossip batch can claim ids for records poldercast drops outright
+/// (e.g. it doesn't recognize the address, or it's already full); bookkeeping
+/// maps like `refresh_times`/`gossip_sources` must only grow for ids that
+/// were actually admitted, not every id a peer merely claimed.
+fn admitted_ids(topology: &Topology, candidates: &HashSet<Id>) -> HashSet<Id> {
+    let nodes = topology.nodes();
+    nodes
+        .all_available_nodes()
+        .iter()
+        .chain(nodes.all_quarantined_nodes().iter())
+        .map(|node| Id::from(node.id().clone()))
+        .filter(|id| candidates.contains(id))
+        .collect()
+}
+
+#[cfg(test)]
+mod staleness_and_dedupe_tests {
+    use super::{dedupe_keep_last_by_key, is_stale};
+    use std::time::Duration;
+
+    #[test]
+    fn record_younger_than_max_age_is_not_stale() {
+        let now = Duration::from_secs(100);
+        assert!(!is_stale(
+            now,
+            Duration::from_secs(95),
+            Duration::from_secs(10)
+        ));
+    }
+
+    #[test]
+    fn record_older_than_max_age_is_stale() {
+        let now = Duration::from_secs(100);
+        assert!(is_stale(
+            now,
+            Duration::from_secs(50),
+            Duration::from_secs(10)
+        ));
+    }
+
+    #[test]
+    fn clock_skew_is_not_treated_as_stale() {
+        let now = Duration::from_secs(10);
+        assert!(!is_stale(
+            now,
+            Duration::from_secs(50),
+            Duration::from_secs(10)
+        ));
+    }
+
+    #[test]
+    fn dedupe_keeps_the_last_value_per_key() {
+        let items = vec![(1, "old"), (2, "kept"), (1, "new")];
+        let result = dedupe_keep_last_by_key(items, |(key, _)| *key);
+        assert_eq!(result, vec![(1, "new"), (2, "kept")]);
+    }
+}
+
+/// number of `partition_health` samples kept to compute the rolling
+/// baseline of `all_available_nodes`.
+const PARTITION_BASELINE_WINDOW: usize = 12;
+/// a reachable fraction below this, combined with a high single-sourced
+/// fraction, is treated as a suspected partition.
+const PARTITION_REACHABLE_THRESHOLD: f64 = 0.5;
+/// fraction of known nodes seen from only one source above which the
+/// topology looks split rather than just quiet.
+const PARTITION_SINGLE_SOURCE_THRESHOLD: f64 = 0.5;
+/// how many quarantined peers to re-gossip with when a partition is
+/// suspected.
+const PARTITION_REPAIR_PEERS: usize = 8;
+
+/// number of unverifiable signed envelopes from a single relaying peer at
+/// which [`P2pTopology::report_gossip_strike`] evicts it outright.
+const GOSSIP_STRIKE_LIMIT: u32 = 3;
+
+/// Reasons a peer can be struck for misbehavior specific to the signed
+/// gossip path, tracked locally since `poldercast::StrikeReason` only
+/// covers the base protocol's own strike conditions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GossipStrikeReason {
+    /// relayed a [`SignedNodeProfile`] whose signature didn't verify.
+    InvalidSignature,
+}
+
+/// Average `baseline`'s samples, falling back to `current` when there's no
+/// history yet (so the very first sample reads as "at baseline" rather than
+/// as a 100% drop).
+fn rolling_average(baseline: &VecDeque<usize>, current: usize) -> f64 {
+    if baseline.is_empty() {
+        current as f64
+    } else {
+        baseline.iter().sum::<usize>() as f64 / baseline.len() as f64
+    }
+}
+
+/// Fraction of `baseline_avg` that `available` represents, capped at `1.0`
+/// since being above baseline isn't "more reachable than reachable".
+fn reachable_fraction(available: usize, baseline_avg: f64) -> f64 {
+    if baseline_avg > 0.0 {
+        (available as f64 / baseline_avg).min(1.0)
+    } else {
+        1.0
+    }
+}
+
+/// `true` once both the reachable fraction has dropped low enough and the
+/// single-sourced fraction has climbed high enough to look like a split
+/// rather than ordinary churn.
+fn is_suspected_partition(reachable_fraction: f64, single_source_fraction: f64) -> bool {
+    reachable_fraction < PARTITION_REACHABLE_THRESHOLD
+        && single_source_fraction > PARTITION_SINGLE_SOURCE_THRESHOLD
+}
+
+#[cfg(test)]
+mod partition_health_tests {
+    use super::{
+        is_suspected_partition, reachable_fraction, rolling_average, PARTITION_BASELINE_WINDOW,
+    };
+    use std::collections::VecDeque;
+
+    #[test]
+    fn rolling_average_falls_back_to_current_when_empty() {
+        assert_eq!(rolling_average(&VecDeque::new(), 42), 42.0);
+    }
+
+    #[test]
+    fn rolling_average_matches_mean_of_samples() {
+        let baseline: VecDeque<usize> = vec![10, 20, 30].into();
+        assert_eq!(rolling_average(&baseline, 999), 20.0);
+        assert!(baseline.len() <= PARTITION_BASELINE_WINDOW);
+    }
+
+    #[test]
+    fn reachable_fraction_is_capped_at_one() {
+        assert_eq!(reachable_fraction(20, 10.0), 1.0);
+    }
+
+    #[test]
+    fn reachable_fraction_with_no_baseline_is_fully_reachable() {
+        assert_eq!(reachable_fraction(0, 0.0), 1.0);
+    }
+
+    #[test]
+    fn partition_suspected_only_when_both_thresholds_cross() {
+        assert!(is_suspected_partition(0.1, 0.9));
+        assert!(!is_suspected_partition(0.9, 0.9));
+        assert!(!is_suspected_partition(0.1, 0.1));
+    }
+}
+
+/// A point-in-time read on whether the topology looks partitioned, based
+/// on how many known nodes have only been vouched for by a single gossip
+/// source and how far `all_available_nodes` has dropped from its rolling
+/// baseline.
+#[derive(Clone, Debug)]
+pub struct PartitionReport {
+    /// estimated fraction of the topology we can currently reach, relative
+    /// to the rolling baseline of `all_available_nodes`.
+    pub reachable_fraction: f64,
+    /// number of known node ids that have only ever been gossiped about by
+    /// a single peer.
+    pub single_sourced_nodes: usize,
+    /// `true` when both the reachable fraction and the single-sourced
+    /// fraction have crossed their thresholds.
+    pub suspected_partition: bool,
+    /// gossip rounds dispatched by [`P2pTopology::attempt_partition_repair`]
+    /// to try to bridge a suspected partition, as `(peer, gossips)` pairs
+    /// the caller is responsible for actually sending over the network.
+    /// Empty unless `suspected_partition` is `true`.
+    pub repair_targets: Vec<(Id, Gossips)>,
+}
+
+/// Number of bits in a [`GossipFilter`]'s bitset.
+const GOSSIP_FILTER_BITS: usize = 2048;
+/// Number of hash functions used per Bloom filter lookup/insertion.
+const GOSSIP_FILTER_HASHES: u32 = 4;
+/// Number of bits used to partition the id space into gossip filter
+/// buckets; buckets are rotated across successive calls to
+/// [`P2pTopology::next_gossip_filter`].
+const GOSSIP_FILTER_MASK_BITS: u32 = 4;
+
+/// A Bloom filter over the id-hashes of nodes already known to its owner,
+/// attached to a pull-style gossip request so the responder only sends back
+/// records the requester is missing (modeled on Solana's CRDS pull
+/// reconciliation).
+///
+/// To keep filters small on large topologies, the id space is partitioned
+/// into `2^mask_bits` buckets; a filter only covers ids whose hash, masked
+/// to `mask_bits` bits, equals `mask`.
+#[derive(Clone)]
+pub struct GossipFilter {
+    mask_bits: u32,
+    mask: u64,
+    bits: Vec<u64>,
+}
+
+impl GossipFilter {
+    fn empty(mask_bits: u32, mask: u64) -> Self {
+        GossipFilter {
+            mask_bits,
+            mask,
+            bits: vec![0u64; GOSSIP_FILTER_BITS / 64],
+        }
+    }
+
+    fn bucket_mask(&self) -> u64 {
+        if self.mask_bits >= 64 {
+            u64::MAX
+        } else {
+            (1u64 << self.mask_bits) - 1
+        }
+    }
+
+    fn in_bucket(&self, id: &Id) -> bool {
+        self.mask_bits == 0 || (Self::slot_hash(id, u32::MAX) & self.bucket_mask()) == self.mask
+    }
+
+    fn insert(&mut self, id: &Id) {
+        for seed in 0..GOSSIP_FILTER_HASHES {
+            let index = Self::slot_hash(id, seed) as usize % GOSSIP_FILTER_BITS;
+            self.bits[index / 64] |= 1 << (index % 64);
+        }
+    }
+
+    /// `true` if `id` is (probably) already known to the filter's owner.
+    pub fn contains(&self, id: &Id) -> bool {
+        (0..GOSSIP_FILTER_HASHES).all(|seed| {
+            let index = Self::slot_hash(id, seed) as usize % GOSSIP_FILTER_BITS;
+            self.bits[index / 64] & (1 << (index % 64)) != 0
+        })
+    }
+
+    fn slot_hash(id: &Id, seed: u32) -> u64 {
+        hash_with_seed(id, seed)
+    }
+}
+
+/// Hash `value` together with `seed`, producing one of the
+/// [`GossipFilter`]'s independent hash functions.
+///
+/// Kept as a free function generic over `T: Hash` so the bucketing and
+/// false-positive behavior it drives can be unit tested with plain keys,
+/// without constructing an [`Id`].
+fn hash_with_seed<T: Hash>(value: &T, seed: u32) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    seed.hash(&mut hasher);
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod gossip_filter_tests {
+    use super::{hash_with_seed, GOSSIP_FILTER_BITS, GOSSIP_FILTER_HASHES};
+
+    /// A `GossipFilter` isn't buildable in tests without an `Id`, so these
+    /// tests exercise the same bit-indexing scheme directly against
+    /// `hash_with_seed` over plain `u64` keys.
+    fn slots(key: u64) -> Vec<usize> {
+        (0..GOSSIP_FILTER_HASHES)
+            .map(|seed| hash_with_seed(&key, seed) as usize % GOSSIP_FILTER_BITS)
+            .collect()
+    }
+
+    #[test]
+    fn inserted_keys_are_never_reported_missing() {
+        let mut bits = vec![0u64; GOSSIP_FILTER_BITS / 64];
+        let inserted: Vec<u64> = (0..200).collect();
+        for key in &inserted {
+            for index in slots(*key) {
+                bits[index / 64] |= 1 << (index % 64);
+            }
+        }
+        for key in &inserted {
+            let present = slots(*key)
+                .into_iter()
+                .all(|index| bits[index / 64] & (1 << (index % 64)) != 0);
+            assert!(present, "key {key} reported as missing after insertion");
+        }
+    }
+
+    #[test]
+    fn distinct_seeds_produce_different_hashes() {
+        let a = hash_with_seed(&42u64, 0);
+        let b = hash_with_seed(&42u64, 1);
+        assert_ne!(a, b);
+    }
+}
+
+/// A weighting function used to bias [`P2pTopology::view`] toward
+/// higher-weight peers, together with the fanout to truncate to.
+type WeightSelection = (usize, Arc<dyn Fn(&Node) -> u64 + Send + Sync>);
+
+/// Re-order `candidates` so that higher-weight ones are more likely to sort
+/// first, then keep only the first `fanout` of them.
+///
+/// This implements Efraimidis–Spirakis weighted reservoir sampling: each
+/// candidate with weight `w_i > 0` draws `u_i` uniform in `(0, 1)` and is
+/// keyed by `u_i^(1/w_i)`, then candidates are sorted by key descending.
+/// Candidates with weight `0` are not ranked by the formula (which would
+/// require dividing by zero); instead they are shuffled uniformly and
+/// appended after the weighted ones, so they remain reachable without
+/// being favored.
+///
+/// Generic over the candidate type so the sampling math can be unit tested
+/// without constructing a real [`Node`].
+fn weighted_shuffle_truncate<T>(
+    candidates: Vec<T>,
+    weight_fn: &(dyn Fn(&T) -> u64 + Send + Sync),
+    fanout: usize,
+) -> Vec<T> {
+    let mut rng = rand::thread_rng();
+
+    let mut weighted = Vec::with_capacity(candidates.len());
+    let mut zero_weight = Vec::new();
+
+    for node in candidates {
+        let weight = weight_fn(&node);
+        if weight == 0 {
+            zero_weight.push(node);
+        } else {
+            let u: f64 = rng.gen_range(f64::MIN_POSITIVE..1.0);
+            let key = u.powf(1.0 / weight as f64);
+            weighted.push((key, node));
+        }
+    }
+
+    weighted.sort_unstable_by(|(a, _), (b, _)| b.partial_cmp(a).unwrap());
+    zero_weight.shuffle(&mut rng);
+
+    weighted
+        .into_iter()
+        .map(|(_, node)| node)
+        .chain(zero_weight)
+        .take(fanout)
+        .collect()
+}
+
+#[cfg(test)]
+mod weighted_shuffle_tests {
+    use super::weighted_shuffle_truncate;
+
+    #[test]
+    fn higher_weight_candidate_wins_most_draws() {
+        let weight = |n: &u32| match *n {
+            1 => 100,
+            _ => 1,
+        };
+        let wins = (0..200)
+            .filter(|_| weighted_shuffle_truncate(vec![1u32, 2, 3], &weight, 1)[0] == 1)
+            .count();
+        assert!(
+            wins > 150,
+            "expected the weight-100 candidate to win most draws, got {wins}/200"
+        );
+    }
+
+    #[test]
+    fn zero_weight_candidates_are_still_reachable() {
+        let weight = |n: &u32| if *n == 1 { 5 } else { 0 };
+        let result = weighted_shuffle_truncate(vec![1u32, 2, 3], &weight, 3);
+        assert_eq!(result.len(), 3);
+        assert!(result.contains(&2));
+        assert!(result.contains(&3));
+    }
+
+    #[test]
+    fn truncates_to_the_requested_fanout() {
+        let weight = |n: &u32| u64::from(*n);
+        let result = weighted_shuffle_truncate(vec![1u32, 2, 3, 4, 5], &weight, 2);
+        assert_eq!(result.len(), 2);
+    }
+}
+
+/// Domain-separation tag mixed into every [`SignedNodeProfile`] signature,
+/// so a signature produced for this purpose can't be replayed as a
+/// signature over unrelated data.
+const SIGNED_PROFILE_DOMAIN: &[u8] = b"jormungandr-gossip-profile-v1";
+
+/// A gossiped [`NodeProfile`] wrapped with proof that it was published by
+/// the node it describes, modeled on libp2p's signed peer records and
+/// Lightning's signed node announcements.
+///
+/// Without this, a relaying peer could inject a forged profile for a third
+/// party during gossip relay; [`P2pTopology::accept_gossips_signed`] rejects
+/// any envelope whose signature doesn't check out against its embedded key.
+#[derive(Clone)]
+pub struct SignedNodeProfile {
+    profile: NodeProfile,
+    public_key: PublicKey<Ed25519>,
+    sequence: u64,
+    signature: Signature<Vec<u8>, Ed25519>,
+}
+
+impl SignedNodeProfile {
+    /// Wrap and sign `profile` as the node owning `secret_key`.
+    ///
+    /// `sequence` must increase with every profile this node publishes, so
+    /// a receiver can tell a fresher envelope from a replayed stale one.
+    pub fn new(profile: NodeProfile, secret_key: &SecretKey<Ed25519>, sequence: u64) -> Self {
+        let public_key = secret_key.to_public();
+        let signature = secret_key.sign(&Self::signed_bytes(&profile, &public_key, sequence));
+        SignedNodeProfile {
+            profile,
+            public_key,
+            sequence,
+            signature,
+        }
+    }
+
+    /// Build the bytes actually signed/verified: the domain tag, the
+    /// signer's key and sequence number, followed by the full canonical
+    /// serialization of `profile` (not just its `id`), so tampering with
+    /// any part of the profile invalidates the signature.
+    fn signed_bytes(
+        profile: &NodeProfile,
+        public_key: &PublicKey<Ed25519>,
+        sequence: u64,
+    ) -> Vec<u8> {
+        let profile_bytes =
+            bincode::serialize(profile).expect("NodeProfile serialization is infallible");
+        domain_separated_bytes(SIGNED_PROFILE_DOMAIN, public_key, sequence, &profile_bytes)
+    }
+
+    /// Verify the envelope's signature, and that its embedded key matches
+    /// the `Id` the contained profile claims to be.
+    fn verify(&self, claimed: &Id) -> bool {
+        if &Id::from(self.public_key.clone()) != claimed {
+            return false;
+        }
+        let bytes = Self::signed_bytes(&self.profile, &self.public_key, self.sequence);
+        matches!(
+            self.signature.verify(&bytes, &self.public_key),
+            Verification::Success
+        )
+    }
+}
+
+/// Concatenate a domain-separation tag, a signer's key, a sequence number,
+/// and an arbitrary payload into the bytes a signature is computed over.
+///
+/// Pulled out of [`SignedNodeProfile::signed_bytes`] so the framing can be
+/// unit tested with a plain byte-slice payload instead of a [`NodeProfile`].
+fn domain_separated_bytes(
+    domain: &[u8],
+    public_key: &PublicKey<Ed25519>,
+    sequence: u64,
+    payload: &[u8],
+) -> Vec<u8> {
+    let mut bytes =
+        Vec::with_capacity(domain.len() + public_key.as_ref().len() + 8 + payload.len());
+    bytes.extend_from_slice(domain);
+    bytes.extend_from_slice(public_key.as_ref());
+    bytes.extend_from_slice(&sequence.to_be_bytes());
+    bytes.extend_from_slice(payload);
+    bytes
+}
+
+#[cfg(test)]
+mod signed_node_profile_tests {
+    use super::{domain_separated_bytes, SIGNED_PROFILE_DOMAIN};
+    use chain_crypto::{Ed25519, SecretKey};
+    use rand::thread_rng;
+
+    #[test]
+    fn differing_payloads_sign_to_differing_bytes() {
+        let secret_key = SecretKey::<Ed25519>::generate(thread_rng());
+        let public_key = secret_key.to_public();
+        let a = domain_separated_bytes(SIGNED_PROFILE_DOMAIN, &public_key, 1, b"profile-a");
+        let b = domain_separated_bytes(SIGNED_PROFILE_DOMAIN, &public_key, 1, b"profile-b");
+        assert_ne!(
+            a, b,
+            "tampering with the payload must change the signed bytes"
+        );
+    }
+
+    #[test]
+    fn sign_then_verify_round_trips() {
+        let secret_key = SecretKey::<Ed25519>::generate(thread_rng());
+        let public_key = secret_key.to_public();
+        let bytes = domain_separated_bytes(SIGNED_PROFILE_DOMAIN, &public_key, 7, b"profile");
+        let signature = secret_key.sign(&bytes);
+        assert!(matches!(
+            signature.verify(&bytes, &public_key),
+            chain_crypto::Verification::Success
+        ));
+    }
+
+    #[test]
+    fn tampered_payload_fails_verification() {
+        let secret_key = SecretKey::<Ed25519>::generate(thread_rng());
+        let public_key = secret_key.to_public();
+        let signed = domain_separated_bytes(SIGNED_PROFILE_DOMAIN, &public_key, 7, b"profile-a");
+        let signature = secret_key.sign(&signed);
+        let tampered = domain_separated_bytes(SIGNED_PROFILE_DOMAIN, &public_key, 7, b"profile-b");
+        assert!(!matches!(
+            signature.verify(&tampered, &public_key),
+            chain_crypto::Verification::Success
+        ));
+    }
+}
+
+/// target size of the layer-1 relay set in [`TurbineLayer`]: a small
+/// group of well-connected peers that receive events first.
+const TURBINE_LAYER1_SIZE: usize = 1 << 10;
+
+fn id_hash(id: &Id) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    id.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Given a candidate's `rank` (0-based position in the globally-ordered
+/// candidate list) and the `total` candidate count, decide its turbine
+/// layer and, for a layer-1 candidate, which layer-2 offsets (0-based from
+/// the start of layer-2) it's responsible for relaying to.
+///
+/// Pulled out of [`TurbineLayer::assignment`] so the layer/children math
+/// can be unit tested without constructing a [`poldercast::Nodes`].
+fn layer_for_rank(rank: usize, total: usize) -> (u8, Vec<usize>) {
+    let layer1_len = total.min(TURBINE_LAYER1_SIZE);
+    if rank < layer1_len {
+        let layer2_len = total - layer1_len;
+        let children = (0..layer2_len)
+            .filter(|offset| offset % layer1_len == rank)
+            .collect();
+        (1, children)
+    } else if rank < total {
+        (2, Vec::new())
+    } else {
+        (0, Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod turbine_layer_tests {
+    use super::layer_for_rank;
+
+    #[test]
+    fn top_ranked_nodes_are_layer_one() {
+        let (layer, _) = layer_for_rank(0, 5000);
+        assert_eq!(layer, 1);
+    }
+
+    #[test]
+    fn low_ranked_node_is_layer_two() {
+        let (layer, children) = layer_for_rank(2000, 5000);
+        assert_eq!(layer, 2);
+        assert!(children.is_empty());
+    }
+
+    #[test]
+    fn layer_one_children_partition_all_of_layer_two() {
+        let total = 2500;
+        let layer1_len = total.min(super::TURBINE_LAYER1_SIZE);
+        let mut covered = std::collections::HashSet::new();
+        for rank in 0..layer1_len {
+            let (layer, children) = layer_for_rank(rank, total);
+            assert_eq!(layer, 1);
+            for child in children {
+                assert!(
+                    covered.insert(child),
+                    "layer-2 offset {child} assigned to more than one layer-1 relay"
+                );
+            }
+        }
+        assert_eq!(covered.len(), total - layer1_len);
+    }
+
+    #[test]
+    fn small_topology_has_no_layer_two() {
+        let (layer, children) = layer_for_rank(0, 3);
+        assert_eq!(layer, 1);
+        assert!(children.is_empty());
+    }
+}
+
+/// A node's position in the turbine-style dissemination tree built by
+/// [`TurbineLayer`]. Layer-1 nodes relay to their `children`; layer-0 and
+/// layer-2 nodes have none, since only layer-1 relays fan out further.
+#[derive(Clone, Debug)]
+pub struct TurbineAssignment {
+    pub layer: u8,
+    pub children: Vec<poldercast::Node>,
+}
+
+/// A [`Layer`] module implementing Solana-style layer-0/1/2 hierarchical
+/// broadcast on top of poldercast, for large-scale fanout.
+///
+/// A small layer-1 set of ~[`TURBINE_LAYER1_SIZE`] well-connected peers
+/// receives events first, and each layer-1 node relays to a disjoint fan of
+/// layer-2 peers computed from the same ordering, bounding per-node fanout
+/// while keeping total reach at `TURBINE_LAYER1_SIZE^2` (2^20+) instead of
+/// flooding flat gossip to every peer.
+///
+/// Node ordering is deterministic given the node set: nodes are ranked by
+/// `weight` (stake, bandwidth, ...), falling back to a hash of their id to
+/// break ties, so every node computes the same tree independently without
+/// needing to gossip the assignment itself. The candidate set always
+/// includes the local node alongside its known peers, so a node's own
+/// weight actually affects its own position in the tree.
+pub struct TurbineLayer<F> {
+    weight: F,
+}
+
+impl<F> TurbineLayer<F>
+where
+    F: Fn(&Id) -> u64,
+{
+    pub fn new(weight: F) -> Self {
+        TurbineLayer { weight }
+    }
+
+    /// Rank `own_id` alongside every id in `peers` by weight, breaking ties
+    /// by a hash of the id. `own_id` is included even though it has no
+    /// corresponding [`poldercast::Node`] of its own.
+    fn ordered_ids(&self, own_id: &Id, peers: &[poldercast::Node]) -> Vec<Id> {
+        let mut ids: Vec<Id> = peers
+            .iter()
+            .map(|node| Id::from(node.id().clone()))
+            .collect();
+        if !ids.contains(own_id) {
+            ids.push(own_id.clone());
+        }
+        ids.sort_unstable_by(|a, b| {
+            let weight_a = (self.weight)(a);
+            let weight_b = (self.weight)(b);
+            weight_b
+                .cmp(&weight_a)
+                .then_with(|| id_hash(a).cmp(&id_hash(b)))
+        });
+        ids
+    }
+
+    /// Compute `own_id`'s layer and, if it's a layer-1 relay, the disjoint
+    /// fan of layer-2 peers assigned to it.
+    pub fn assignment(&self, own_id: &Id, nodes: &poldercast::Nodes) -> TurbineAssignment {
+        let peers: Vec<poldercast::Node> =
+            nodes.all_available_nodes().into_iter().cloned().collect();
+        let ordered = self.ordered_ids(own_id, &peers);
+        let rank = ordered
+            .iter()
+            .position(|id| id == own_id)
+            .expect("own_id is always present in the candidate set by construction");
+        let (layer, child_offsets) = layer_for_rank(rank, ordered.len());
+
+        let layer1_len = ordered.len().min(TURBINE_LAYER1_SIZE);
+        let layer2_ids = &ordered[layer1_len..];
+        let children = child_offsets
+            .into_iter()
+            .filter_map(|offset| {
+                let id = layer2_ids.get(offset)?;
+                peers
+                    .iter()
+                    .find(|node| &Id::from(node.id().clone()) == id)
+                    .cloned()
+            })
+            .collect();
+
+        TurbineAssignment { layer, children }
+    }
+}
+
+impl<F> Layer for TurbineLayer<F>
+where
+    F: Fn(&Id) -> u64 + Send + Sync,
+{
+    fn alias(&self) -> &'static str {
+        "TurbineLayer"
+    }
+
+    fn reset(&mut self) {}
+
+    fn populate(&mut self, _identity: &NodeProfile, _new_node: &Node) {}
+
+    fn view(&mut self, nodes: &poldercast::Nodes, _selection: poldercast::Selection) -> Vec<Node> {
+        // This layer doesn't contribute to the flat gossip view: its value
+        // is the turbine tree assignment exposed through
+        // `P2pTopology::turbine_assignment`, not an additional candidate set.
+        let _ = nodes;
+        Vec::new()
+    }
+}
 
 // object holding a count of available, unreachable and quarantined nodes.
 #[derive(Clone)]
@@ -19,14 +754,16 @@ pub struct NodeCount {
     all_available_nodes: usize,
     all_unreachable_nodes: usize,
     all_quarantined_nodes: usize,
+    all_purged_nodes: usize,
 }
 
 impl NodeCount {
-    pub fn new(nodes: &poldercast::Nodes) -> Self {
+    pub fn new(nodes: &poldercast::Nodes, all_purged_nodes: usize) -> Self {
         NodeCount {
             all_available_nodes: nodes.all_available_nodes().len(),
             all_unreachable_nodes: nodes.all_unreachable_nodes().len(),
             all_quarantined_nodes: nodes.all_quarantined_nodes().len(),
+            all_purged_nodes,
         }
     }
 
@@ -41,6 +778,12 @@ impl NodeCount {
     pub fn all_quarantined_nodes_count(&self) -> usize {
         self.all_quarantined_nodes
     }
+
+    /// total number of stale node records removed by
+    /// [`P2pTopology::purge_stale`] since this node started.
+    pub fn all_purged_nodes_count(&self) -> usize {
+        self.all_purged_nodes
+    }
 }
 
 /// object holding the P2pTopology of the Node
@@ -48,6 +791,15 @@ impl NodeCount {
 pub struct P2pTopology {
     lock: Arc<RwLock<Topology>>,
     logger: Logger,
+    weight_selection: Option<WeightSelection>,
+    gossip_bucket_cursor: Arc<AtomicU64>,
+    refresh_times: Arc<RwLock<HashMap<Id, Duration>>>,
+    known_sequences: Arc<RwLock<HashMap<Id, u64>>>,
+    purged_nodes: Arc<AtomicU64>,
+    gossip_sources: Arc<RwLock<HashMap<Id, HashSet<Id>>>>,
+    available_baseline: Arc<RwLock<VecDeque<usize>>>,
+    turbine_weight: Option<Arc<dyn Fn(&Id) -> u64 + Send + Sync>>,
+    gossip_strikes: Arc<RwLock<HashMap<Id, u32>>>,
 }
 
 impl P2pTopology {
@@ -55,9 +807,49 @@ impl P2pTopology {
     ///
     /// The address is the public
     pub fn new(node: poldercast::NodeProfile, logger: Logger) -> Self {
+        // seed a refresh time for our own id. Nothing refreshes it later;
+        // `purge_stale` and `initiate_gossips_since` exempt our own id
+        // explicitly instead of relying on this entry being fresh.
+        let own_id = Id::from(node.id().clone());
+        let mut refresh_times = HashMap::new();
+        refresh_times.insert(own_id, now_unix());
+
         P2pTopology {
             lock: Arc::new(RwLock::new(Topology::new(node))),
             logger,
+            weight_selection: None,
+            gossip_bucket_cursor: Arc::new(AtomicU64::new(0)),
+            refresh_times: Arc::new(RwLock::new(refresh_times)),
+            known_sequences: Arc::new(RwLock::new(HashMap::new())),
+            purged_nodes: Arc::new(AtomicU64::new(0)),
+            gossip_sources: Arc::new(RwLock::new(HashMap::new())),
+            available_baseline: Arc::new(RwLock::new(VecDeque::with_capacity(
+                PARTITION_BASELINE_WINDOW,
+            ))),
+            turbine_weight: None,
+            gossip_strikes: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// record that `ids` were just (re-)seen, resetting their staleness
+    /// clock for [`purge_stale`](Self::purge_stale).
+    fn touch_refresh_times(&self, ids: impl Iterator<Item = Id>) {
+        let now = now_unix();
+        let mut refresh_times = self.refresh_times.write().unwrap();
+        for id in ids {
+            refresh_times.insert(id, now);
+        }
+    }
+
+    /// record that `from` vouched for each of `ids` in this gossip round,
+    /// for [`partition_health`](Self::partition_health)'s coverage metric.
+    fn record_gossip_sources(&self, from: Id, ids: impl Iterator<Item = Id>) {
+        let mut gossip_sources = self.gossip_sources.write().unwrap();
+        for id in ids {
+            gossip_sources
+                .entry(id)
+                .or_insert_with(HashSet::new)
+                .insert(from.clone());
         }
     }
 
@@ -73,6 +865,36 @@ impl P2pTopology {
         topology.add_layer(module)
     }
 
+    /// Plug in a [`TurbineLayer`] for hierarchical, turbine-style
+    /// dissemination, ranking candidates by `weight` (e.g. stake).
+    ///
+    /// `weight` is kept around so [`turbine_assignment`](Self::turbine_assignment)
+    /// can recompute this node's place in the tree on demand, independently
+    /// of the layer's participation in [`view`](Self::view). It's keyed by
+    /// [`Id`] rather than [`poldercast::Node`] so it can weigh the local
+    /// node, which has no `Node` of its own in the topology.
+    pub fn add_turbine_layer<F>(&mut self, weight: F)
+    where
+        F: Fn(&Id) -> u64 + Send + Sync + 'static,
+    {
+        let weight = Arc::new(weight);
+        self.turbine_weight = Some(weight.clone());
+        self.add_module(TurbineLayer::new(move |id| weight(id)));
+    }
+
+    /// This node's position in the turbine dissemination tree: which layer
+    /// it's in, and, if it's a layer-1 relay, the disjoint fan of layer-2
+    /// peers it's responsible for relaying to.
+    ///
+    /// Returns `None` if [`add_turbine_layer`](Self::add_turbine_layer) was
+    /// never called.
+    pub fn turbine_assignment(&self) -> Option<TurbineAssignment> {
+        let weight = self.turbine_weight.as_ref()?;
+        let own_id = Id::from(self.node().id().clone());
+        let topology = self.lock.read().unwrap();
+        Some(TurbineLayer::new(|id| weight(id)).assignment(&own_id, topology.nodes()))
+    }
+
     pub fn set_policy(&mut self, policy: PolicyConfig) {
         let mut topology = self.lock.write().unwrap();
         topology.set_policy(Policy::new(
@@ -89,15 +911,38 @@ impl P2pTopology {
         topology.add_layer(Cyclon::default());
     }
 
+    /// Bias [`view`](Self::view) toward higher-weight peers (e.g. those
+    /// carrying more stake or bandwidth) instead of treating every candidate
+    /// neighbor uniformly.
+    ///
+    /// The resulting view is produced by a weighted random shuffle of the
+    /// candidate set truncated to `fanout`, so dissemination stays
+    /// probabilistic rather than deterministically pinned to the
+    /// highest-weight peers.
+    pub fn set_weight_function<F>(&mut self, fanout: usize, weight: F)
+    where
+        F: Fn(&Node) -> u64 + Send + Sync + 'static,
+    {
+        self.weight_selection = Some((fanout, Arc::new(weight)));
+    }
+
     /// Returns a list of neighbors selected in this turn
     /// to contact for event dissemination.
     pub fn view(&self, selection: poldercast::Selection) -> Vec<Node> {
         let mut topology = self.lock.write().unwrap();
-        topology
+        let candidates: Vec<Node> = topology
             .view(None, selection)
             .into_iter()
             .map(Node::new)
-            .collect()
+            .collect();
+        drop(topology);
+
+        match &self.weight_selection {
+            Some((fanout, weight_fn)) => {
+                weighted_shuffle_truncate(candidates, weight_fn.as_ref(), *fanout)
+            }
+            None => candidates,
+        }
     }
 
     pub fn initiate_gossips(&self, with: Id) -> Gossips {
@@ -105,9 +950,215 @@ impl P2pTopology {
         topology.initiate_gossips(with.into()).into()
     }
 
+    /// Build a [`GossipFilter`] covering the given `mask`/`mask_bits` bucket,
+    /// populated with the ids of nodes we already know about.
+    pub fn gossip_filter(&self, mask_bits: u32, mask: u64) -> GossipFilter {
+        let topology = self.lock.read().unwrap();
+        let mut filter = GossipFilter::empty(mask_bits, mask);
+        for node in topology.nodes().all_available_nodes() {
+            let id = Id::from(node.id());
+            if filter.in_bucket(&id) {
+                filter.insert(&id);
+            }
+        }
+        filter
+    }
+
+    /// Build a [`GossipFilter`] over the next bucket in rotation.
+    ///
+    /// Rotating buckets across successive gossip rounds means the full id
+    /// space gets reconciled over time without ever shipping a filter large
+    /// enough to cover it all at once.
+    pub fn next_gossip_filter(&self) -> GossipFilter {
+        let bucket = self.gossip_bucket_cursor.fetch_add(1, Ordering::Relaxed)
+            % (1 << GOSSIP_FILTER_MASK_BITS);
+        self.gossip_filter(GOSSIP_FILTER_MASK_BITS, bucket)
+    }
+
+    /// Like [`initiate_gossips`](Self::initiate_gossips), but only returns
+    /// gossip records whose id hash is not already present in `filter`.
+    ///
+    /// `filter` is expected to have been built by `with` from their own
+    /// current node set (see [`gossip_filter`](Self::gossip_filter)), so
+    /// this avoids re-sending records they likely already have, cutting
+    /// redundant gossip bandwidth on large topologies.
+    pub fn initiate_gossips_filtered(&self, with: Id, filter: &GossipFilter) -> Gossips {
+        let mut topology = self.lock.write().unwrap();
+        let gossips: Vec<poldercast::Gossip> = topology.initiate_gossips(with.into()).into();
+        drop(topology);
+        gossips
+            .into_iter()
+            .filter(|gossip| !filter.contains(&Id::from(gossip.id())))
+            .collect::<Vec<_>>()
+            .into()
+    }
+
     pub fn accept_gossips(&self, from: Id, gossips: Gossips) {
+        let profiles: Vec<poldercast::Gossip> = gossips.into();
+        // last-version-wins: if the same id shows up more than once in this
+        // batch, only the last occurrence is kept.
+        let profiles = dedupe_keep_last_by_key(profiles, |gossip| Id::from(gossip.id()));
+        let candidate_ids: HashSet<Id> = profiles
+            .iter()
+            .map(|gossip| Id::from(gossip.id()))
+            .collect();
+
+        let mut topology = self.lock.write().unwrap();
+        topology.accept_gossips(from.clone().into(), profiles.into());
+        // only bookkeep ids poldercast actually admitted, not every id a
+        // peer merely claimed in the batch, so a peer can't grow these maps
+        // unboundedly with fabricated ids poldercast itself drops.
+        let admitted = admitted_ids(&topology, &candidate_ids);
+        drop(topology);
+
+        self.touch_refresh_times(admitted.iter().cloned());
+        self.record_gossip_sources(from, admitted.into_iter());
+    }
+
+    /// Like [`initiate_gossips`](Self::initiate_gossips), but only includes
+    /// records we've refreshed within `not_older_than`.
+    ///
+    /// A node that just restarted re-gossips everything it remembers; this
+    /// lower bound keeps it from flooding `with` with entries that were
+    /// already ancient before the restart. A record with no refresh time at
+    /// all (never seen since `refresh_times` was last reset) is excluded
+    /// rather than let through, since we can't vouch for how fresh it is.
+    /// Our own profile is always let through, however old its seeded entry
+    /// in `refresh_times` (see [`new`](Self::new)) is.
+    pub fn initiate_gossips_since(&self, with: Id, not_older_than: Duration) -> Gossips {
+        let now = now_unix();
+        let own_id = Id::from(self.node().id().clone());
+        let refresh_times = self.refresh_times.read().unwrap();
+
+        let mut topology = self.lock.write().unwrap();
+        let gossips: Vec<poldercast::Gossip> = topology.initiate_gossips(with.into()).into();
+        drop(topology);
+
+        gossips
+            .into_iter()
+            .filter(|gossip| {
+                let id = Id::from(gossip.id());
+                if id == own_id {
+                    return true;
+                }
+                // a record we've never refreshed is not "refreshed within
+                // `not_older_than`" by definition, so it must be excluded
+                // rather than let through.
+                refresh_times.get(&id).map_or(false, |&last_seen| {
+                    !is_stale(now, last_seen, not_older_than)
+                })
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// Remove node records that haven't been refreshed within `older_than`,
+    /// analogous to Solana's CRDS pull timeout and Lightning's
+    /// `GossipTimestampFilter`. Our own profile and actively-connected peers
+    /// are never purged, even if their record hasn't refreshed recently.
+    ///
+    /// Also clears `gossip_sources`, `known_sequences`, and `gossip_strikes`
+    /// for each purged id, so none of these bookkeeping maps outlive the
+    /// node record they describe.
+    ///
+    /// Returns the number of records purged; the running total is reported
+    /// through [`nodes_count`](Self::nodes_count).
+    pub fn purge_stale(&self, older_than: Duration) -> usize {
+        let now = now_unix();
+        let own_id = Id::from(self.node().id().clone());
+
+        let mut topology = self.lock.write().unwrap();
+        let active: HashSet<Id> = topology
+            .nodes()
+            .all_available_nodes()
+            .iter()
+            .map(|node| Id::from(node.id().clone()))
+            .collect();
+
+        let mut refresh_times = self.refresh_times.write().unwrap();
+        let stale: Vec<Id> = refresh_times
+            .iter()
+            .filter(|(id, last_seen)| {
+                **id != own_id && !active.contains(*id) && is_stale(now, **last_seen, older_than)
+            })
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        let mut gossip_sources = self.gossip_sources.write().unwrap();
+        let mut known_sequences = self.known_sequences.write().unwrap();
+        let mut gossip_strikes = self.gossip_strikes.write().unwrap();
+        for id in &stale {
+            topology.remove_node(id.clone().into());
+            refresh_times.remove(id);
+            gossip_sources.remove(id);
+            known_sequences.remove(id);
+            gossip_strikes.remove(id);
+        }
+
+        self.purged_nodes
+            .fetch_add(stale.len() as u64, Ordering::Relaxed);
+        stale.len()
+    }
+
+    /// Like [`accept_gossips`](Self::accept_gossips), but for gossip records
+    /// wrapped in a [`SignedNodeProfile`] envelope.
+    ///
+    /// Envelopes that fail verification are dropped rather than merged into
+    /// the topology, and `from` is struck with
+    /// [`GossipStrikeReason::InvalidSignature`] via
+    /// [`report_gossip_strike`](Self::report_gossip_strike), since a
+    /// relaying peer handed us a profile it can't prove belongs to the node
+    /// it claims to describe.
+    pub fn accept_gossips_signed(&self, from: Id, envelopes: Vec<SignedNodeProfile>) {
+        let mut verified = Vec::with_capacity(envelopes.len());
+        let mut known_sequences = self.known_sequences.write().unwrap();
+        for envelope in envelopes {
+            let claimed = Id::from(envelope.profile.id().clone());
+            if !envelope.verify(&claimed) {
+                warn!(
+                    self.logger,
+                    "rejecting unverifiable signed node profile relayed by {}", from
+                );
+                self.report_gossip_strike(from.clone(), GossipStrikeReason::InvalidSignature);
+                continue;
+            }
+
+            // last-version-wins: drop an envelope that's no newer than one
+            // we've already accepted for the same id.
+            let is_newer = known_sequences
+                .get(&claimed)
+                .map_or(true, |&seen| envelope.sequence > seen);
+            if !is_newer {
+                continue;
+            }
+            known_sequences.insert(claimed, envelope.sequence);
+            verified.push(envelope.profile);
+        }
+        drop(known_sequences);
+
+        // a batch can carry several envelopes with strictly increasing
+        // sequences for the same id, each passing the check above against
+        // the *previous* one; collapse to the highest-sequence envelope per
+        // id (the last survivor, since `verified` is in increasing-sequence
+        // order per id) instead of handing poldercast's own accept path
+        // more than one profile to resolve per id.
+        let verified = dedupe_keep_last_by_key(verified, |profile| Id::from(profile.id().clone()));
+        let candidate_ids: HashSet<Id> = verified
+            .iter()
+            .map(|profile| Id::from(profile.id().clone()))
+            .collect();
+
+        let gossips: Vec<poldercast::Gossip> =
+            verified.into_iter().map(poldercast::Gossip::from).collect();
         let mut topology = self.lock.write().unwrap();
-        topology.accept_gossips(from.into(), gossips.into())
+        topology.accept_gossips(from.clone().into(), gossips.into());
+        // as in `accept_gossips`, only bookkeep ids poldercast actually
+        // admitted, not every id a verified envelope merely claimed.
+        let admitted = admitted_ids(&topology, &candidate_ids);
+        drop(topology);
+
+        self.touch_refresh_times(admitted.iter().cloned());
+        self.record_gossip_sources(from, admitted.into_iter());
     }
 
     pub fn exchange_gossips(&mut self, with: Id, gossips: Gossips) -> Gossips {
@@ -125,6 +1176,94 @@ impl P2pTopology {
         self.lock.write().unwrap().force_reset_layers()
     }
 
+    /// Sample the current gossip coverage and check whether the topology
+    /// looks partitioned, inspired by Solana's approach to detecting and
+    /// repairing partitions.
+    ///
+    /// A node is "single-sourced" if we've only ever heard it gossiped
+    /// about by one peer; a large cluster of those, together with
+    /// `all_available_nodes` dropping well below its rolling baseline,
+    /// suggests we're stuck talking to only one side of a split. When that
+    /// happens this also forces a layer reset and computes a fresh gossip
+    /// round against a handful of randomly chosen quarantined peers, to try
+    /// to bridge back to the other side; those rounds come back in
+    /// [`PartitionReport::repair_targets`] for the caller to actually send.
+    pub fn partition_health(&self) -> PartitionReport {
+        let available = {
+            let topology = self.lock.read().unwrap();
+            topology.nodes().all_available_nodes().len()
+        };
+
+        let rolling_avg = {
+            let mut baseline = self.available_baseline.write().unwrap();
+            let avg = rolling_average(&baseline, available);
+            baseline.push_back(available);
+            if baseline.len() > PARTITION_BASELINE_WINDOW {
+                baseline.pop_front();
+            }
+            avg
+        };
+
+        let (single_sourced_nodes, single_source_fraction) = {
+            let gossip_sources = self.gossip_sources.read().unwrap();
+            let single_sourced = gossip_sources
+                .values()
+                .filter(|sources| sources.len() <= 1)
+                .count();
+            let fraction = if gossip_sources.is_empty() {
+                0.0
+            } else {
+                single_sourced as f64 / gossip_sources.len() as f64
+            };
+            (single_sourced, fraction)
+        };
+
+        let reachable_fraction = reachable_fraction(available, rolling_avg);
+
+        let suspected_partition =
+            is_suspected_partition(reachable_fraction, single_source_fraction);
+
+        let repair_targets = if suspected_partition {
+            self.attempt_partition_repair()
+        } else {
+            Vec::new()
+        };
+
+        PartitionReport {
+            reachable_fraction,
+            single_sourced_nodes,
+            suspected_partition,
+            repair_targets,
+        }
+    }
+
+    /// Force a fresh gossip round against a handful of quarantined peers
+    /// and reset the topology layers, in an attempt to bridge a suspected
+    /// partition.
+    ///
+    /// Returns the actual gossip rounds computed for each selected peer;
+    /// the caller must dispatch these over the network for the repair
+    /// attempt to have any effect.
+    fn attempt_partition_repair(&self) -> Vec<(Id, Gossips)> {
+        warn!(
+            self.logger,
+            "suspected network partition: forcing a topology layer reset and a fresh gossip round"
+        );
+        self.force_reset_layers();
+
+        let mut quarantined = self.list_quarantined();
+        quarantined.shuffle(&mut rand::thread_rng());
+        quarantined
+            .into_iter()
+            .take(PARTITION_REPAIR_PEERS)
+            .map(|node| {
+                let id = Id::from(node.id().clone());
+                let gossips = self.initiate_gossips(id.clone());
+                (id, gossips)
+            })
+            .collect()
+    }
+
     pub fn list_quarantined(&self) -> Vec<poldercast::Node> {
         self.lock
             .read()
@@ -159,7 +1298,10 @@ impl P2pTopology {
     }
 
     pub fn nodes_count(&self) -> NodeCount {
-        NodeCount::new(self.lock.read().unwrap().nodes())
+        NodeCount::new(
+            self.lock.read().unwrap().nodes(),
+            self.purged_nodes.load(Ordering::Relaxed) as usize,
+        )
     }
 
     /// register a strike against the given node id
@@ -172,4 +1314,30 @@ impl P2pTopology {
             node.record_mut().strike(issue);
         })
     }
+
+    /// register a strike against `node` for a [`GossipStrikeReason`] not
+    /// covered by `poldercast::StrikeReason`, evicting it once it has
+    /// accumulated [`GOSSIP_STRIKE_LIMIT`] of them.
+    ///
+    /// Returns `true` if this strike caused `node` to be evicted.
+    pub fn report_gossip_strike(&self, node: Id, issue: GossipStrikeReason) -> bool {
+        let count = {
+            let mut strikes = self.gossip_strikes.write().unwrap();
+            let count = strikes.entry(node.clone()).or_insert(0);
+            *count += 1;
+            *count
+        };
+        warn!(
+            self.logger,
+            "struck {} for {:?} ({}/{})", node, issue, count, GOSSIP_STRIKE_LIMIT
+        );
+        if count >= GOSSIP_STRIKE_LIMIT {
+            self.gossip_strikes.write().unwrap().remove(&node);
+            let mut topology = self.lock.write().unwrap();
+            topology.remove_node(node.into());
+            true
+        } else {
+            false
+        }
+    }
 }